@@ -2,7 +2,8 @@ mod browse {
     use crate::categories::Category;
     use crate::common::{Pagination, V2BulkResult};
     use crate::media::MediaType;
-    use crate::{enum_values, options, Crunchyroll, Locale, MediaCollection, Request, Result};
+    use super::SearchResult;
+    use crate::{enum_values, Crunchyroll, Locale, MediaCollection, Request, Result};
     use futures_util::FutureExt;
     use serde::Deserialize;
 
@@ -42,32 +43,230 @@ mod browse {
             Popularity = "popularity"
             NewlyAdded = "newly_added"
             Alphabetical = "alphabetical"
+            Trending = "trending"
         }
     }
 
-    options! {
-        BrowseOptions;
+    enum_values! {
+        /// Maturity / content rating a browsed entry may have.
+        pub enum ContentRating {
+            Family = "family"
+            Teen = "teen"
+            Mature = "mature"
+        }
+    }
+
+    /// Options how to browse the Crunchyroll catalog.
+    ///
+    /// The scalar setters ([`BrowseOptions::sort`], [`BrowseOptions::is_dubbed`], …) can be chained
+    /// directly. For larger filter combinations — multiple categories or media types, excluded
+    /// categories, a content rating — use the fluent [`BrowseOptionsBuilder`] via
+    /// [`BrowseOptions::builder`]. All multi-value fields are serialized as a single
+    /// comma-separated query parameter rather than repeated keys.
+    #[derive(Clone, Debug)]
+    pub struct BrowseOptions {
         /// Specifies the categories of the entries.
-        categories(Vec<Category>, "categories") = None,
+        pub categories: Vec<Category>,
+        /// Specifies categories the entries must **not** belong to.
+        pub not_categories: Vec<Category>,
         /// Specifies whether the entries should be dubbed.
-        is_dubbed(bool, "is_dubbed") = None,
+        pub is_dubbed: Option<bool>,
         /// Specifies whether the entries should be subbed.
-        is_subbed(bool, "is_subbed") = None,
+        pub is_subbed: Option<bool>,
         /// Specifies a particular simulcast season in which the entries should have been aired. Use
         /// [`Crunchyroll::simulcast_seasons`] to get all seasons.
-        simulcast_season(String, "season_tag") = None,
+        pub simulcast_season: Option<String>,
+        /// Specifies how the entries should be sorted.
+        pub sort: Option<BrowseSortType>,
+        /// Specifies the media types of the entries. Multiple types are comma-joined into one
+        /// `type` parameter.
+        pub media_types: Vec<MediaType>,
+        /// Restricts the entries to the given content / maturity rating.
+        pub content_rating: Option<ContentRating>,
+        /// Preferred audio language.
+        pub preferred_audio_language: Option<Locale>,
+    }
+
+    impl Default for BrowseOptions {
+        fn default() -> Self {
+            Self {
+                categories: vec![],
+                not_categories: vec![],
+                is_dubbed: None,
+                is_subbed: None,
+                simulcast_season: None,
+                sort: Some(BrowseSortType::NewlyAdded),
+                media_types: vec![],
+                content_rating: None,
+                preferred_audio_language: None,
+            }
+        }
+    }
+
+    impl BrowseOptions {
+        /// Starts a fluent [`BrowseOptionsBuilder`].
+        pub fn builder() -> BrowseOptionsBuilder {
+            BrowseOptionsBuilder::default()
+        }
+
+        /// Specifies the categories of the entries.
+        pub fn categories(mut self, categories: Vec<Category>) -> Self {
+            self.categories = categories;
+            self
+        }
+        /// Specifies categories the entries must **not** belong to.
+        pub fn not_categories(mut self, not_categories: Vec<Category>) -> Self {
+            self.not_categories = not_categories;
+            self
+        }
+        /// Restricts the entries to the given content / maturity rating.
+        pub fn content_rating(mut self, content_rating: ContentRating) -> Self {
+            self.content_rating = Some(content_rating);
+            self
+        }
+        /// Specifies whether the entries should be dubbed.
+        pub fn is_dubbed(mut self, is_dubbed: bool) -> Self {
+            self.is_dubbed = Some(is_dubbed);
+            self
+        }
+        /// Specifies whether the entries should be subbed.
+        pub fn is_subbed(mut self, is_subbed: bool) -> Self {
+            self.is_subbed = Some(is_subbed);
+            self
+        }
+        /// Specifies a particular simulcast season in which the entries should have been aired.
+        pub fn simulcast_season<S: Into<String>>(mut self, simulcast_season: S) -> Self {
+            self.simulcast_season = Some(simulcast_season.into());
+            self
+        }
+        /// Specifies how the entries should be sorted.
+        pub fn sort(mut self, sort: BrowseSortType) -> Self {
+            self.sort = Some(sort);
+            self
+        }
+        /// Specifies a single media type of the entries.
+        pub fn media_type(mut self, media_type: MediaType) -> Self {
+            self.media_types = vec![media_type];
+            self
+        }
+        /// Specifies the media types of the entries at once.
+        pub fn media_types<I: IntoIterator<Item = MediaType>>(mut self, media_types: I) -> Self {
+            self.media_types = media_types.into_iter().collect();
+            self
+        }
+        /// Preferred audio language.
+        pub fn preferred_audio_language(mut self, preferred_audio_language: Locale) -> Self {
+            self.preferred_audio_language = Some(preferred_audio_language);
+            self
+        }
+
+        fn into_query(self) -> Vec<(String, String)> {
+            let mut query: Vec<(String, String)> = vec![];
+            if !self.categories.is_empty() {
+                query.push(("categories".to_string(), join(&self.categories)));
+            }
+            if !self.not_categories.is_empty() {
+                query.push(("not_categories".to_string(), join(&self.not_categories)));
+            }
+            if let Some(is_dubbed) = self.is_dubbed {
+                query.push(("is_dubbed".to_string(), is_dubbed.to_string()));
+            }
+            if let Some(is_subbed) = self.is_subbed {
+                query.push(("is_subbed".to_string(), is_subbed.to_string()));
+            }
+            if let Some(simulcast_season) = self.simulcast_season {
+                query.push(("season_tag".to_string(), simulcast_season));
+            }
+            if let Some(sort) = self.sort {
+                query.push(("sort".to_string(), sort.to_string()));
+            }
+            if !self.media_types.is_empty() {
+                query.push(("type".to_string(), join(&self.media_types)));
+            }
+            if let Some(content_rating) = self.content_rating {
+                query.push(("content_rating".to_string(), content_rating.to_string()));
+            }
+            if let Some(preferred_audio_language) = self.preferred_audio_language {
+                query.push((
+                    "preferred_audio_language".to_string(),
+                    preferred_audio_language.to_string(),
+                ));
+            }
+            query
+        }
+    }
+
+    fn join<T: ToString>(values: &[T]) -> String {
+        values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    /// A fluent builder for [`BrowseOptions`] which makes large, multi-value filter combinations
+    /// ergonomic to assemble.
+    #[derive(Clone, Debug, Default)]
+    pub struct BrowseOptionsBuilder {
+        options: BrowseOptions,
+    }
+
+    impl BrowseOptionsBuilder {
+        /// Adds a category the entries must belong to.
+        pub fn category(mut self, category: Category) -> Self {
+            self.options.categories.push(category);
+            self
+        }
+        /// Adds a category the entries must **not** belong to.
+        pub fn exclude_category(mut self, category: Category) -> Self {
+            self.options.not_categories.push(category);
+            self
+        }
+        /// Sets the media types the entries may have at once.
+        pub fn media_types<I: IntoIterator<Item = MediaType>>(mut self, media_types: I) -> Self {
+            self.options.media_types = media_types.into_iter().collect();
+            self
+        }
+        /// Restricts the entries to the given content / maturity rating.
+        pub fn content_rating(mut self, content_rating: ContentRating) -> Self {
+            self.options.content_rating = Some(content_rating);
+            self
+        }
+        /// Specifies whether the entries should be dubbed.
+        pub fn is_dubbed(mut self, is_dubbed: bool) -> Self {
+            self.options.is_dubbed = Some(is_dubbed);
+            self
+        }
+        /// Specifies whether the entries should be subbed.
+        pub fn is_subbed(mut self, is_subbed: bool) -> Self {
+            self.options.is_subbed = Some(is_subbed);
+            self
+        }
         /// Specifies how the entries should be sorted.
-        sort(BrowseSortType, "sort") = Some(BrowseSortType::NewlyAdded),
-        /// Specifies the media type of the entries.
-        media_type(MediaType, "type") = None,
+        pub fn sort(mut self, sort: BrowseSortType) -> Self {
+            self.options.sort = Some(sort);
+            self
+        }
+        /// Specifies a particular simulcast season in which the entries should have been aired.
+        pub fn simulcast_season<S: Into<String>>(mut self, simulcast_season: S) -> Self {
+            self.options.simulcast_season = Some(simulcast_season.into());
+            self
+        }
         /// Preferred audio language.
-        preferred_audio_language(Locale, "preferred_audio_language") = None
+        pub fn preferred_audio_language(mut self, preferred_audio_language: Locale) -> Self {
+            self.options.preferred_audio_language = Some(preferred_audio_language);
+            self
+        }
+        /// Finalizes the builder into [`BrowseOptions`].
+        pub fn build(self) -> BrowseOptions {
+            self.options
+        }
     }
 
     impl Crunchyroll {
         /// Browses the crunchyroll catalog filtered by the specified options and returns all found
         /// series and movies.
-        pub fn browse(&self, options: BrowseOptions) -> Pagination<MediaCollection> {
+        pub fn browse(&self, options: BrowseOptions) -> Pagination<SearchResult<MediaCollection>> {
             Pagination::new(
                 |options| {
                     async move {
@@ -78,7 +277,7 @@ mod browse {
                             .get(endpoint)
                             .query(&options.query)
                             .query(&[("n", options.page_size), ("start", options.start)])
-                            .request::<V2BulkResult<MediaCollection>>()
+                            .request::<V2BulkResult<SearchResult<MediaCollection>>>()
                             .await?;
                         Ok((result.data, result.total))
                     }
@@ -89,6 +288,12 @@ mod browse {
             )
         }
 
+        /// Browses the catalog for the titles which are popular / trending right now, sorted by
+        /// [`BrowseSortType::Trending`]. A convenience entry point on top of [`Crunchyroll::browse`].
+        pub fn trending(&self) -> Pagination<SearchResult<MediaCollection>> {
+            self.browse(BrowseOptions::default().sort(BrowseSortType::Trending))
+        }
+
         /// Returns all simulcast seasons. The locale specified which language the localization /
         /// human readable name ([`SimulcastSeasonLocalization::title`]) has.
         pub async fn simulcast_seasons(&self, locale: Locale) -> Result<Vec<SimulcastSeason>> {
@@ -107,128 +312,432 @@ mod browse {
 mod query {
     use crate::common::{Pagination, V2BulkResult, V2TypeBulkResult};
     use crate::media::{Episode, MovieListing, Series};
-    use crate::{Crunchyroll, MediaCollection};
+    use crate::{enum_values, Crunchyroll, MediaCollection, Request, Result};
+    use chrono::{DateTime, Utc};
     use futures_util::FutureExt;
+    use serde::Deserialize;
+
+    /// Per-item ranking signals returned by the Crunchyroll search endpoints. These power custom
+    /// re-ranking or "why am I seeing this" UIs; the API returns them alongside a result when it
+    /// ranked it.
+    #[derive(Clone, Debug, Default, Deserialize)]
+    #[serde(default)]
+    pub struct SearchMetadata {
+        /// Position of the item within the search ranking.
+        pub rank: Option<u32>,
+        /// Relevance score of the item for the issued query.
+        pub score: f64,
+        /// Popularity score; only populated for recommendation-style results.
+        pub popularity_score: Option<f64>,
+        /// When the item was made public.
+        pub last_public: Option<DateTime<Utc>>,
+    }
+
+    /// A single search / browse result together with its optional [`SearchMetadata`].
+    ///
+    /// The media item itself deserializes exactly like the bare object it wraps (so
+    /// [`SearchResult::result`] is just a [`MediaCollection`] / [`Series`] / …). The sibling
+    /// ranking keys — if the endpoint returned any — are split off into [`SearchResult::metadata`]
+    /// *before* the remaining keys are handed to the inner type. This keeps the media type's own
+    /// `deny_unknown_fields` (under `__test_strict`) intact — it never sees `rank`/`score`/… — and
+    /// lets `metadata` be a genuine `None` when no ranking signals were present, rather than a
+    /// zero-valued struct indistinguishable from a real zero score.
+    #[derive(Clone, Debug, Default, Request)]
+    #[request(executor(result))]
+    pub struct SearchResult<T: Request + Default + for<'de2> Deserialize<'de2>> {
+        pub result: T,
+        pub metadata: Option<SearchMetadata>,
+    }
+
+    impl<'de, T> Deserialize<'de> for SearchResult<T>
+    where
+        T: Request + Default + for<'de2> Deserialize<'de2>,
+    {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error;
+
+            let mut map = serde_json::Map::deserialize(deserializer)?;
+            let mut ranking = serde_json::Map::new();
+            for key in ["rank", "score", "popularity_score", "last_public"] {
+                if let Some(value) = map.remove(key) {
+                    ranking.insert(key.to_string(), value);
+                }
+            }
+            let metadata = if ranking.is_empty() {
+                None
+            } else {
+                Some(
+                    serde_json::from_value(serde_json::Value::Object(ranking))
+                        .map_err(Error::custom)?,
+                )
+            };
+            let result =
+                serde_json::from_value(serde_json::Value::Object(map)).map_err(Error::custom)?;
+            Ok(SearchResult { result, metadata })
+        }
+    }
+
+    enum_values! {
+        /// The type of results [`Crunchyroll::query`] should return. Used via
+        /// [`QueryOptions::result_type`] to restrict the search to specific kinds of media.
+        pub enum QueryType {
+            TopResults = "top_results"
+            Series = "series"
+            MovieListing = "movie_listing"
+            Episode = "episode"
+        }
+    }
+
+    impl Default for QueryType {
+        fn default() -> Self {
+            QueryType::TopResults
+        }
+    }
+
+    /// Options how to query the Crunchyroll catalog.
+    #[derive(Clone, Debug, Default)]
+    pub struct QueryOptions {
+        result_type: Vec<QueryType>,
+    }
+
+    impl QueryOptions {
+        /// Restrict the search to the given result types. If left empty, every type is queried and
+        /// all fields of [`QueryResults`] are populated.
+        pub fn result_type(mut self, result_type: Vec<QueryType>) -> Self {
+            self.result_type = result_type;
+            self
+        }
+    }
+
+    /// A lightweight autocomplete entry returned by [`Crunchyroll::query_suggestions`]. Unlike the
+    /// heavy [`MediaCollection`]s from [`Crunchyroll::query`] it only carries the bare minimum
+    /// needed to render a search-as-you-type dropdown.
+    #[derive(Clone, Debug, Default, Deserialize, Request)]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct QuerySuggestion {
+        pub id: String,
+        pub title: String,
+        #[serde(rename = "type")]
+        pub result_type: QueryType,
+    }
 
     /// Results when querying Crunchyroll. Results depending on the input which was given via
     /// [`QueryOptions::result_type`]. If not specified, every field is populated, if one specific
     /// type, for example [`QueryType::Series`], were provided, only [`QueryResults::series`] will
     /// be populated.
     pub struct QueryResults {
-        pub top_results: Pagination<MediaCollection>,
-        pub series: Pagination<Series>,
-        pub movie_listing: Pagination<MovieListing>,
-        pub episode: Pagination<Episode>,
+        pub top_results: Pagination<SearchResult<MediaCollection>>,
+        pub series: Pagination<SearchResult<Series>>,
+        pub movie_listing: Pagination<SearchResult<MovieListing>>,
+        pub episode: Pagination<SearchResult<Episode>>,
     }
 
     impl Crunchyroll {
-        /// Search the Crunchyroll catalog by a given query / string.
-        pub fn query<S: AsRef<str>>(&self, query: S) -> QueryResults {
+        /// Search the Crunchyroll catalog by a given query / string. The [`QueryOptions`] decide
+        /// which result types are fetched: each requested type is backed by its own lazily
+        /// paginated [`QueryResults`] field, and only those fields are populated. Restricting the
+        /// types therefore fires one request per requested type instead of always four, and every
+        /// request asks for — and downloads — only the single type it extracts.
+        ///
+        /// Note: this deliberately keeps one independent [`Pagination`] per result type rather than
+        /// issuing a single combined request with a comma-joined `type` list and splitting the
+        /// resulting `V2BulkResult<V2TypeBulkResult<_>>` across the fields. Each field paginates
+        /// on its own (different `start`/page sizes, loaded on demand), which a shared one-shot
+        /// fetch cannot express; a multi-type restriction thus still issues one request per type.
+        pub fn query<S: AsRef<str>>(&self, query: S, options: QueryOptions) -> QueryResults {
+            let types = if options.result_type.is_empty() {
+                vec![
+                    QueryType::TopResults,
+                    QueryType::Series,
+                    QueryType::MovieListing,
+                    QueryType::Episode,
+                ]
+            } else {
+                options.result_type
+            };
+            let wants = |t: &str| types.iter().any(|ty| ty.to_string() == t);
+            let query = query.as_ref().to_string();
+
             QueryResults {
-                top_results: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<MediaCollection>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&options.query)
-                                .query(&[("type", "top_results")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let top_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "top_results")
-                                .unwrap_or_default();
-                            Ok((top_results.items, top_results.total))
-                        }
-                        .boxed()
-                    },
-                    self.executor.clone(),
-                    vec![("q".to_string(), query.as_ref().to_string())],
-                ),
-                series: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<Series>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&options.query)
-                                .query(&[("type", "series")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let top_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "series")
-                                .unwrap_or_default();
-                            Ok((top_results.items, top_results.total))
-                        }
-                        .boxed()
-                    },
-                    self.executor.clone(),
-                    vec![("q".to_string(), query.as_ref().to_string())],
-                ),
-                movie_listing: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<MovieListing>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&options.query)
-                                .query(&[("type", "movie_listing")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let top_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "movie_listing")
-                                .unwrap_or_default();
-                            Ok((top_results.items, top_results.total))
-                        }
-                        .boxed()
-                    },
-                    self.executor.clone(),
-                    vec![("q".to_string(), query.as_ref().to_string())],
-                ),
-                episode: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<Episode>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&options.query)
-                                .query(&[("type", "episode")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let top_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "episode")
-                                .unwrap_or_default();
-                            Ok((top_results.items, top_results.total))
-                        }
-                        .boxed()
-                    },
-                    self.executor.clone(),
-                    vec![("q".to_string(), query.as_ref().to_string())],
-                ),
+                top_results: if wants("top_results") {
+                    Pagination::new(
+                        |options| {
+                            async move {
+                                let endpoint =
+                                    "https://www.crunchyroll.com/content/v2/discover/search";
+                                let result: V2BulkResult<V2TypeBulkResult<SearchResult<MediaCollection>>> =
+                                    options
+                                        .executor
+                                        .get(endpoint)
+                                        .query(&options.query)
+                                        .query(&[("type", "top_results")])
+                                        .query(&[("limit", options.page_size), ("start", options.start)])
+                                        .apply_locale_query()
+                                        .request()
+                                        .await?;
+                                let top_results = result
+                                    .data
+                                    .into_iter()
+                                    .find(|r| r.result_type == "top_results")
+                                    .unwrap_or_default();
+                                Ok((top_results.items, top_results.total))
+                            }
+                            .boxed()
+                        },
+                        self.executor.clone(),
+                        vec![("q".to_string(), query.clone())],
+                    )
+                } else {
+                    Pagination::new(
+                        |_| async move { Ok((vec![], 0)) }.boxed(),
+                        self.executor.clone(),
+                        vec![],
+                    )
+                },
+                series: if wants("series") {
+                    Pagination::new(
+                        |options| {
+                            async move {
+                                let endpoint =
+                                    "https://www.crunchyroll.com/content/v2/discover/search";
+                                let result: V2BulkResult<V2TypeBulkResult<SearchResult<Series>>> = options
+                                    .executor
+                                    .get(endpoint)
+                                    .query(&options.query)
+                                    .query(&[("type", "series")])
+                                    .query(&[("limit", options.page_size), ("start", options.start)])
+                                    .apply_locale_query()
+                                    .request()
+                                    .await?;
+                                let series = result
+                                    .data
+                                    .into_iter()
+                                    .find(|r| r.result_type == "series")
+                                    .unwrap_or_default();
+                                Ok((series.items, series.total))
+                            }
+                            .boxed()
+                        },
+                        self.executor.clone(),
+                        vec![("q".to_string(), query.clone())],
+                    )
+                } else {
+                    Pagination::new(
+                        |_| async move { Ok((vec![], 0)) }.boxed(),
+                        self.executor.clone(),
+                        vec![],
+                    )
+                },
+                movie_listing: if wants("movie_listing") {
+                    Pagination::new(
+                        |options| {
+                            async move {
+                                let endpoint =
+                                    "https://www.crunchyroll.com/content/v2/discover/search";
+                                let result: V2BulkResult<V2TypeBulkResult<SearchResult<MovieListing>>> = options
+                                    .executor
+                                    .get(endpoint)
+                                    .query(&options.query)
+                                    .query(&[("type", "movie_listing")])
+                                    .query(&[("limit", options.page_size), ("start", options.start)])
+                                    .apply_locale_query()
+                                    .request()
+                                    .await?;
+                                let movie_listing = result
+                                    .data
+                                    .into_iter()
+                                    .find(|r| r.result_type == "movie_listing")
+                                    .unwrap_or_default();
+                                Ok((movie_listing.items, movie_listing.total))
+                            }
+                            .boxed()
+                        },
+                        self.executor.clone(),
+                        vec![("q".to_string(), query.clone())],
+                    )
+                } else {
+                    Pagination::new(
+                        |_| async move { Ok((vec![], 0)) }.boxed(),
+                        self.executor.clone(),
+                        vec![],
+                    )
+                },
+                episode: if wants("episode") {
+                    Pagination::new(
+                        |options| {
+                            async move {
+                                let endpoint =
+                                    "https://www.crunchyroll.com/content/v2/discover/search";
+                                let result: V2BulkResult<V2TypeBulkResult<SearchResult<Episode>>> = options
+                                    .executor
+                                    .get(endpoint)
+                                    .query(&options.query)
+                                    .query(&[("type", "episode")])
+                                    .query(&[("limit", options.page_size), ("start", options.start)])
+                                    .apply_locale_query()
+                                    .request()
+                                    .await?;
+                                let episode = result
+                                    .data
+                                    .into_iter()
+                                    .find(|r| r.result_type == "episode")
+                                    .unwrap_or_default();
+                                Ok((episode.items, episode.total))
+                            }
+                            .boxed()
+                        },
+                        self.executor.clone(),
+                        vec![("q".to_string(), query)],
+                    )
+                } else {
+                    Pagination::new(
+                        |_| async move { Ok((vec![], 0)) }.boxed(),
+                        self.executor.clone(),
+                        vec![],
+                    )
+                },
             }
         }
+
+        /// Returns lightweight autocomplete suggestions for a partially typed query. This is meant
+        /// to be called on every keystroke of a search box, so it is intentionally cheap and
+        /// returns a plain [`Vec`] rather than a [`Pagination`] like [`Crunchyroll::query`].
+        pub async fn query_suggestions<S: AsRef<str>>(
+            &self,
+            partial: S,
+        ) -> Result<Vec<QuerySuggestion>> {
+            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search/suggestions";
+            Ok(self
+                .executor
+                .get(endpoint)
+                .query(&[("q", partial.as_ref())])
+                .apply_locale_query()
+                .request::<V2BulkResult<QuerySuggestion>>()
+                .await?
+                .data)
+        }
+    }
+}
+
+mod feed {
+    use crate::common::{Pagination, V2BulkResult};
+    use crate::{Crunchyroll, MediaCollection, Request, Result};
+    use futures_util::FutureExt;
+    use serde::Deserialize;
+
+    /// A single panel / rail of the personalized home feed. Carries its own id, a localized
+    /// title and description and the [`MediaCollection`]s which belong to it. The same shape backs
+    /// an individually fetched curated feed panel, see [`CuratedFeed`].
+    #[derive(Clone, Debug, Default, Deserialize, Request)]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct HomeFeedItem {
+        pub id: String,
+        pub title: String,
+        pub description: String,
+        #[serde(alias = "panels")]
+        pub items: Vec<MediaCollection>,
+    }
+
+    /// A curated feed panel, fetchable on its own via [`CuratedFeed::from_id`]. A curated feed has
+    /// the same shape as a [`HomeFeedItem`], just retrieved for one specific feed id.
+    pub type CuratedFeed = HomeFeedItem;
+
+    impl HomeFeedItem {
+        /// Fetches a single curated feed panel by its id.
+        pub async fn from_id<S: AsRef<str>>(
+            crunchyroll: &Crunchyroll,
+            id: S,
+        ) -> Result<CuratedFeed> {
+            let endpoint = format!(
+                "https://www.crunchyroll.com/content/v2/discover/curated_feed/{}",
+                id.as_ref()
+            );
+            Ok(crunchyroll
+                .executor
+                .get(endpoint)
+                .apply_locale_query()
+                .request::<V2BulkResult<CuratedFeed>>()
+                .await?
+                .data
+                .into_iter()
+                .next()
+                .unwrap_or_default())
+        }
+    }
+
+    impl Crunchyroll {
+        /// Returns the personalized home feed as shown on the Crunchyroll landing page. Each item
+        /// is a curated rail of [`MediaCollection`]s.
+        pub fn home_feed(&self) -> Pagination<HomeFeedItem> {
+            Pagination::new(
+                |options| {
+                    async move {
+                        let endpoint =
+                            "https://www.crunchyroll.com/content/v2/discover/home_feed";
+                        let result = options
+                            .executor
+                            .clone()
+                            .get(endpoint)
+                            .query(&options.query)
+                            .query(&[("n", options.page_size), ("start", options.start)])
+                            .apply_locale_query()
+                            .request::<V2BulkResult<HomeFeedItem>>()
+                            .await?;
+                        Ok((result.data, result.total))
+                    }
+                    .boxed()
+                },
+                self.executor.clone(),
+                vec![],
+            )
+        }
+    }
+}
+
+mod similar {
+    use super::SearchResult;
+    use crate::common::{Pagination, V2BulkResult};
+    use crate::{Crunchyroll, MediaCollection};
+    use futures_util::FutureExt;
+
+    impl Crunchyroll {
+        /// Returns titles similar to the given series or movie listing, turning an isolated search
+        /// hit (obtained via [`Crunchyroll::query`] or [`Crunchyroll::browse`]) into a navigable
+        /// recommendation graph. The results carry a populated
+        /// [`SearchMetadata::popularity_score`](super::SearchMetadata::popularity_score) so clients
+        /// can weight the recommendations.
+        pub fn similar<S: AsRef<str>>(&self, id: S) -> Pagination<SearchResult<MediaCollection>> {
+            let id = id.as_ref().to_string();
+            Pagination::new(
+                |options| {
+                    async move {
+                        let endpoint =
+                            "https://www.crunchyroll.com/content/v2/discover/similar_to";
+                        let result = options
+                            .executor
+                            .clone()
+                            .get(endpoint)
+                            .query(&options.query)
+                            .query(&[("n", options.page_size), ("start", options.start)])
+                            .apply_locale_query()
+                            .request::<V2BulkResult<SearchResult<MediaCollection>>>()
+                            .await?;
+                        Ok((result.data, result.total))
+                    }
+                    .boxed()
+                },
+                self.executor.clone(),
+                vec![("guid".to_string(), id)],
+            )
+        }
     }
 }
 
 pub use browse::*;
+pub use feed::*;
 pub use query::*;